@@ -5,6 +5,10 @@ use crate::coins::Coin;
 #[cfg(feature = "stargate")]
 use crate::ibc::IbcMsg;
 use enclave_cosmwasm_types::encoding::Binary;
+#[cfg(feature = "stargate")]
+use enclave_cosmwasm_types::math::Decimal;
+#[cfg(feature = "stargate")]
+use enclave_cosmwasm_types::timestamp::Timestamp;
 
 use super::Empty;
 
@@ -35,6 +39,8 @@ where
     Wasm(WasmMsg),
     #[cfg(feature = "stargate")]
     Gov(GovMsg),
+    #[cfg(feature = "stargate")]
+    Authz(AuthzMsg),
 }
 
 /// The message types of the bank module.
@@ -100,6 +106,12 @@ pub enum DistributionMsg {
         /// The `validator_address`
         validator: String,
     },
+    /// This is translated to a [MsgFundCommunityPool](https://github.com/cosmos/cosmos-sdk/blob/v0.42.4/proto/cosmos/distribution/v1beta1/tx.proto#L53-L61).
+    /// `depositor` is automatically filled with the current contract's address.
+    FundCommunityPool {
+        /// The amount to spend
+        amount: Vec<Coin>,
+    },
 }
 
 /// The message types of the wasm module.
@@ -145,6 +157,74 @@ pub enum WasmMsg {
         /// that are originating from other contracts
         callback_sig: Option<Vec<u8>>,
     },
+    /// Instantiates a new contract from previously uploaded Wasm code using a predictable,
+    /// salt-derived address.
+    ///
+    /// This is translated to a [MsgInstantiateContract2](https://github.com/CosmWasm/wasmd/blob/v0.29.0/proto/cosmwasm/wasm/v1/tx.proto#L86-L108).
+    /// `sender` is automatically filled with the current contract's address.
+    ///
+    /// The resulting contract's address is derived from the creator's address, the `code_id`/checksum, and
+    /// `salt` alone, so it can be predicted off-chain before the message is even sent (e.g. to pre-fund it or
+    /// have another message reference it). `salt` must be non-empty, and the derivation must match the
+    /// enclave/wasmd address algorithm for off-chain tooling to reproduce the same address.
+    Instantiate2 {
+        code_id: u64,
+        /// code_hash is the hex encoded hash of the code. This is used by Secret Network to harden against replaying the contract
+        /// It is used to bind the request to a destination contract in a stronger way than just the contract address which can be faked
+        code_hash: String,
+        /// msg is the JSON-encoded InstantiateMsg struct (as raw Binary)
+        msg: Binary,
+        #[serde(rename = "send")]
+        funds: Vec<Coin>,
+        /// A human-readbale label for the contract
+        label: String,
+        /// salt is used to turn the resulting contract address into a deterministic one. Must be non-empty.
+        salt: Binary,
+        /// callback_sig is used only inside the enclave to validate messages
+        /// that are originating from other contracts
+        callback_sig: Option<Vec<u8>>,
+    },
+    /// Migrates a previously instantiated contract to a new code version, in place.
+    ///
+    /// This is translated to a [MsgMigrateContract](https://github.com/CosmWasm/wasmd/blob/v0.14.0/x/wasm/internal/types/tx.proto#L100-L111).
+    /// `sender` is automatically filled with the current contract's address.
+    /// The contract's admin must be the current contract for this to succeed.
+    Migrate {
+        /// Contract address
+        contract_addr: String,
+        /// code_hash is the hex encoded hash of the new code. This is used by Secret Network to harden against replaying the contract
+        /// It is used to bind the request to a destination contract in a stronger way than just the contract address which can be faked
+        code_hash: String,
+        /// msg is the json-encoded MigrateMsg struct (as raw Binary)
+        msg: Binary,
+        /// callback_sig is used only inside the enclave to validate messages
+        /// that are originating from other contracts
+        callback_sig: Option<Vec<u8>>,
+    },
+    /// Sets a new admin for a contract, who can migrate it or update its admin.
+    ///
+    /// This is translated to a [MsgUpdateAdmin](https://github.com/CosmWasm/wasmd/blob/v0.14.0/x/wasm/internal/types/tx.proto#L113-L121).
+    /// `sender` is automatically filled with the current contract's address, and must be the contract's current admin.
+    UpdateAdmin {
+        /// Contract address
+        contract_addr: String,
+        /// The address of the new admin
+        new_admin: String,
+        /// callback_sig is used only inside the enclave to validate messages
+        /// that are originating from other contracts
+        callback_sig: Option<Vec<u8>>,
+    },
+    /// Clears the admin of a contract, permanently disabling migrations and admin updates.
+    ///
+    /// This is translated to a [MsgClearAdmin](https://github.com/CosmWasm/wasmd/blob/v0.14.0/x/wasm/internal/types/tx.proto#L123-L129).
+    /// `sender` is automatically filled with the current contract's address, and must be the contract's current admin.
+    ClearAdmin {
+        /// Contract address
+        contract_addr: String,
+        /// callback_sig is used only inside the enclave to validate messages
+        /// that are originating from other contracts
+        callback_sig: Option<Vec<u8>>,
+    },
 }
 
 #[cfg(feature = "stargate")]
@@ -153,6 +233,12 @@ pub enum WasmMsg {
 pub enum GovMsg {
     /// This maps directly to [MsgVote](https://github.com/cosmos/cosmos-sdk/blob/v0.42.5/proto/cosmos/gov/v1beta1/tx.proto#L46-L56) in the Cosmos SDK with voter set to the contract address.
     Vote { proposal_id: u64, vote: VoteOption },
+    /// This maps directly to [MsgVoteWeighted](https://github.com/cosmos/cosmos-sdk/blob/v0.42.5/proto/cosmos/gov/v1beta1/tx.proto#L58-L68) in the Cosmos SDK with voter set to the contract address.
+    /// Unlike `Vote`, this allows splitting voting power across multiple options. The weights of `options` must sum to 1.
+    VoteWeighted {
+        proposal_id: u64,
+        options: Vec<WeightedVoteOption>,
+    },
 }
 
 #[cfg(feature = "stargate")]
@@ -165,6 +251,49 @@ pub enum VoteOption {
     NoWithVeto,
 }
 
+/// A single option and its weight, as used by [GovMsg::VoteWeighted].
+#[cfg(feature = "stargate")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+    pub weight: Decimal,
+}
+
+/// The message types of the authz module.
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.0/proto/cosmos/authz/v1beta1/tx.proto
+#[cfg(feature = "stargate")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthzMsg {
+    /// This is translated to a [MsgGrant](https://github.com/cosmos/cosmos-sdk/blob/v0.45.0/proto/cosmos/authz/v1beta1/tx.proto#L35-L41).
+    /// `granter` is automatically filled with the current contract's address.
+    Grant {
+        /// The address that is being granted the authorization
+        grantee: String,
+        /// The authorization itself, encoded as a protobuf `Any` (e.g. a `GenericAuthorization` or `SendAuthorization`)
+        authorization: Binary,
+        /// The time at which the grant expires. `None` means the grant never expires.
+        expiration: Option<Timestamp>,
+    },
+    /// Executes messages on behalf of a granter that has authorized the current contract to do so via a prior `Grant`.
+    ///
+    /// This is translated to a [MsgExec](https://github.com/cosmos/cosmos-sdk/blob/v0.45.0/proto/cosmos/authz/v1beta1/tx.proto#L53-L59).
+    /// `grantee` is automatically filled with the current contract's address.
+    Exec {
+        /// The messages to execute, each encoded as a protobuf `Any`
+        msgs: Vec<Binary>,
+    },
+    /// This is translated to a [MsgRevoke](https://github.com/cosmos/cosmos-sdk/blob/v0.45.0/proto/cosmos/authz/v1beta1/tx.proto#L44-L50).
+    /// `granter` is automatically filled with the current contract's address.
+    Revoke {
+        /// The address whose authorization is being revoked
+        grantee: String,
+        /// The type URL of the grant being revoked, e.g. `/cosmos.bank.v1beta1.MsgSend`
+        msg_type_url: String,
+    },
+}
+
 impl<T> From<BankMsg> for CosmosMsg<T>
 where
     T: Clone + fmt::Debug + PartialEq,
@@ -222,3 +351,13 @@ where
         CosmosMsg::Gov(msg)
     }
 }
+
+#[cfg(feature = "stargate")]
+impl<T> From<AuthzMsg> for CosmosMsg<T>
+where
+    T: Clone + fmt::Debug + PartialEq,
+{
+    fn from(msg: AuthzMsg) -> Self {
+        CosmosMsg::Authz(msg)
+    }
+}